@@ -5,4 +5,31 @@ fn main() {
     let net_fact: usize = args.get(2).unwrap().parse().unwrap();
     spike_bloom::bloom_test_suite(data_count, net_fact);
     spike_bloom::rehash_test_suite(data_count, net_fact);
+    spike_bloom::bloom_partitioned_test_suite(data_count, net_fact);
+    spike_bloom::merkle_rehash_test_suite(data_count, net_fact);
+    spike_bloom::push_gossip_test_suite(data_count, net_fact);
+    spike_bloom::topology_comparison_test_suite(data_count, net_fact);
+    spike_bloom::bloom_churn_test_suite(data_count, net_fact);
+    spike_bloom::rehash_churn_test_suite(data_count, net_fact);
+    spike_bloom::adversarial_test_suite(
+        "bloom_spurious",
+        data_count,
+        net_fact,
+        spike_bloom::bloom_filter_sync_first_map_to_others,
+        || Box::new(spike_bloom::SpuriousHashAdversary { fraction: 0.1 }),
+    );
+    spike_bloom::adversarial_test_suite(
+        "rehash_drop_node",
+        data_count,
+        net_fact,
+        spike_bloom::rehash_filter_sync_first_map_to_others,
+        || Box::new(spike_bloom::DropNodeAdversary::new(0.1)),
+    );
+    spike_bloom::adversarial_test_suite(
+        "bloom_reorder",
+        data_count,
+        net_fact,
+        spike_bloom::bloom_filter_sync_first_map_to_others,
+        || Box::new(spike_bloom::ReorderAdversary),
+    );
 }
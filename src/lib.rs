@@ -1,4 +1,5 @@
 use rand::prelude::*;
+use rand::rngs::StdRng;
 use std::collections::HashSet;
 use std::sync::Arc;
 
@@ -7,8 +8,7 @@ type Map = HashSet<Hash>;
 type Node = Vec<Map>;
 type Network = Vec<Node>;
 
-fn rand_hash() -> Hash {
-    let mut rng = rand::thread_rng();
+fn rand_hash(rng: &mut impl Rng) -> Hash {
     let mut out = [0; 32];
     rng.fill(&mut out[..]);
     Arc::new(out)
@@ -25,27 +25,27 @@ fn gen_hash(d: &[u8]) -> Hash {
     Arc::new(out)
 }
 
-fn gen_map(data_count: usize) -> Map {
+fn gen_map(rng: &mut impl Rng, data_count: usize) -> Map {
     let mut out = HashSet::new();
     for _ in 0..data_count {
-        let h = rand_hash();
+        let h = rand_hash(rng);
         out.insert(h);
     }
     out
 }
 
-fn gen_node(data_count: usize, net_fact: usize) -> Node {
+fn gen_node(rng: &mut impl Rng, data_count: usize, net_fact: usize) -> Node {
     let mut out = Vec::new();
     for _ in 0..net_fact {
-        out.push(gen_map(data_count));
+        out.push(gen_map(rng, data_count));
     }
     out
 }
 
-fn gen_network(data_count: usize, net_fact: usize) -> Network {
+fn gen_network(rng: &mut impl Rng, data_count: usize, net_fact: usize) -> Network {
     let mut out = Vec::new();
     for _ in 0..net_fact {
-        out.push(gen_node(data_count, net_fact));
+        out.push(gen_node(rng, data_count, net_fact));
     }
     out
 }
@@ -72,12 +72,11 @@ fn is_network_consistent(network: &Network) -> bool {
     true
 }
 
-fn shuffle_network(network: &mut Network) {
-    let mut rng = rand::thread_rng();
+fn shuffle_network(rng: &mut impl Rng, network: &mut Network) {
     for node in network.iter_mut() {
-        node.shuffle(&mut rng);
+        node.shuffle(rng);
     }
-    network.shuffle(&mut rng);
+    network.shuffle(rng);
 }
 
 fn sync_node(node: &mut Node) {
@@ -150,7 +149,7 @@ fn bloom_filter_sync_two_maps(map1: &mut Map, map2: &mut Map) -> BytesTransferre
     byte_tx
 }
 
-fn bloom_filter_sync_first_map_to_others(network: &mut Network) -> BytesTransferred {
+pub fn bloom_filter_sync_first_map_to_others(network: &mut Network) -> BytesTransferred {
     let mut byte_tx = 0;
 
     let mut first_node = network.remove(0);
@@ -167,37 +166,853 @@ fn bloom_filter_sync_first_map_to_others(network: &mut Network) -> BytesTransfer
     byte_tx
 }
 
+// Partitioned (sharded) bloom filters, modeled on Solana's CRDS filter set.
+//
+// Instead of one `Bloom` over the whole map, the hash space is split into
+// `2^mask_bits` partitions by the top `mask_bits` bits of each hash, sized so
+// each partition holds roughly `PARTITION_TARGET_COUNT` items. Only one
+// partition, chosen at random, is exchanged per sync call, so a large map
+// is reconciled over several bounded-size rounds instead of one giant
+// transfer.
+const PARTITION_TARGET_COUNT: usize = 512;
+
+fn partition_mask_bits(data_count: usize) -> u32 {
+    if data_count <= PARTITION_TARGET_COUNT {
+        return 0;
+    }
+    let partitions = (data_count as f64 / PARTITION_TARGET_COUNT as f64).ceil();
+    partitions.log2().ceil() as u32
+}
+
+fn hash_prefix(h: &Hash, mask_bits: u32) -> u32 {
+    if mask_bits == 0 {
+        return 0;
+    }
+    let v = u32::from_be_bytes([h[0], h[1], h[2], h[3]]);
+    v >> (32 - mask_bits)
+}
+
+struct PartitionedBloom {
+    mask_bits: u32,
+    prefix: u32,
+    bloom: bloomfilter::Bloom<Hash>,
+}
+
+fn gen_partitioned_bloom_for_map(map: &Map, mask_bits: u32, prefix: u32) -> PartitionedBloom {
+    const TGT_FP: f64 = 0.01;
+
+    let count = map
+        .iter()
+        .filter(|h| hash_prefix(h, mask_bits) == prefix)
+        .count();
+
+    let mut bloom = bloomfilter::Bloom::new_for_fp_rate(count.max(1), TGT_FP);
+
+    for h in map.iter().filter(|h| hash_prefix(h, mask_bits) == prefix) {
+        bloom.set(h);
+    }
+
+    PartitionedBloom {
+        mask_bits,
+        prefix,
+        bloom,
+    }
+}
+
+fn bloom_partitioned_filter_sync_two_maps(
+    rng: &mut impl Rng,
+    map1: &mut Map,
+    map2: &mut Map,
+) -> BytesTransferred {
+    const BLOOM_OVERHEAD: BytesTransferred = 0
+        + 8 // bitmap bits
+        + 4 // k_num
+        + (8 * 4) // sip_keys
+        + 4 // mask_bits
+        + 4 // prefix
+        ;
+
+    let mask_bits = partition_mask_bits(map1.len().max(map2.len()));
+    let partition_count = 1u32 << mask_bits;
+    let prefix = rng.gen_range(0..partition_count);
+
+    let mut byte_tx = 0;
+
+    let filter1 = gen_partitioned_bloom_for_map(map1, mask_bits, prefix);
+    let filter2 = gen_partitioned_bloom_for_map(map2, mask_bits, prefix);
+
+    for h in map1
+        .iter()
+        .filter(|h| hash_prefix(h, mask_bits) == prefix)
+        .cloned()
+        .collect::<Vec<_>>()
+    {
+        if !filter2.bloom.check(&h) {
+            byte_tx += h.len();
+            map2.insert(h);
+        }
+    }
+
+    for h in map2
+        .iter()
+        .filter(|h| hash_prefix(h, mask_bits) == prefix)
+        .cloned()
+        .collect::<Vec<_>>()
+    {
+        if !filter1.bloom.check(&h) {
+            byte_tx += h.len();
+            map1.insert(h);
+        }
+    }
+
+    byte_tx += BLOOM_OVERHEAD + filter1.bloom.bitmap().len();
+    byte_tx += BLOOM_OVERHEAD + filter2.bloom.bitmap().len();
+
+    byte_tx
+}
+
+fn bloom_partitioned_filter_sync_first_map_to_others(
+    rng: &mut impl Rng,
+    network: &mut Network,
+) -> BytesTransferred {
+    let mut byte_tx = 0;
+
+    let mut first_node = network.remove(0);
+    {
+        let first_map = first_node.get_mut(0).unwrap();
+
+        for node in network.iter_mut() {
+            byte_tx +=
+                bloom_partitioned_filter_sync_two_maps(rng, node.get_mut(0).unwrap(), first_map);
+        }
+    }
+
+    network.push(first_node);
+
+    byte_tx
+}
+
 fn hash_of_hashes<'a, I: IntoIterator<Item = &'a Hash>>(hashes: I) -> Hash {
     let mut uber_hash = Vec::new();
     for hash in hashes.into_iter() {
         uber_hash.extend_from_slice(&hash[..]);
     }
-    gen_hash(&uber_hash)
+    gen_hash(&uber_hash)
+}
+
+fn rehash_filter_sync_two_maps(map1: &mut Map, map2: &mut Map) -> BytesTransferred {
+    let mut byte_tx = 0;
+    let hash1 = hash_of_hashes(map1.iter());
+    let hash2 = hash_of_hashes(map2.iter());
+    byte_tx += 32 + 32;
+
+    if hash1 != hash2 {
+        // node 1 sends all hashes
+        byte_tx += map1.len() * 32;
+
+        // node 2 requests ops it doesn't have from node 1
+        for h in map1.iter() {
+            if !map2.contains(h) {
+                byte_tx += h.len();
+                map2.insert(h.clone());
+            }
+        }
+
+        // node 2 forwards ops it has that node 1 doesn't
+        for h in map2.iter() {
+            if !map1.contains(h) {
+                byte_tx += h.len();
+                map1.insert(h.clone());
+            }
+        }
+    }
+
+    byte_tx
+}
+
+// Recursive Merkle-prefix reconciliation, inspired by OpenEthereum's
+// split-into-subchains sync. Rather than sending the whole set the moment
+// the top-level `hash_of_hashes` disagrees, the hash space is bucketed by
+// `MERKLE_BUCKET_BITS` bits at a time; only the `2^MERKLE_BUCKET_BITS`
+// bucket digests (32 bytes each) are exchanged, and only mismatching
+// buckets are recursed into, consuming the next `MERKLE_BUCKET_BITS` bits.
+// Once a bucket holds at most `MERKLE_LEAF_MAX` items, recursion stops and
+// the raw hash list for just that bucket is exchanged.
+const MERKLE_BUCKET_BITS: u32 = 4;
+const MERKLE_LEAF_MAX: usize = 4;
+
+fn merkle_bucket_index(h: &Hash, bit_offset: u32, bits: u32) -> u32 {
+    let mut v: u32 = 0;
+    for i in 0..bits {
+        let bit_pos = bit_offset + i;
+        let byte_idx = (bit_pos / 8) as usize;
+        let bit_in_byte = 7 - (bit_pos % 8);
+        let bit = (h[byte_idx] >> bit_in_byte) & 1;
+        v = (v << 1) | bit as u32;
+    }
+    v
+}
+
+fn merkle_bucket_members(hashes: &[Hash], bit_offset: u32, bits: u32, bucket: u32) -> Vec<&Hash> {
+    hashes
+        .iter()
+        .filter(|h| merkle_bucket_index(h, bit_offset, bits) == bucket)
+        .collect()
+}
+
+fn merkle_bucket_digest(members: &[&Hash]) -> Hash {
+    hash_of_hashes(members.iter().copied())
+}
+
+fn merkle_reconcile(
+    hashes1: &[Hash],
+    hashes2: &[Hash],
+    bit_offset: u32,
+    map1: &mut Map,
+    map2: &mut Map,
+) -> BytesTransferred {
+    let mut byte_tx = 0;
+    let bucket_count = 1u32 << MERKLE_BUCKET_BITS;
+
+    for bucket in 0..bucket_count {
+        let members1 = merkle_bucket_members(hashes1, bit_offset, MERKLE_BUCKET_BITS, bucket);
+        let members2 = merkle_bucket_members(hashes2, bit_offset, MERKLE_BUCKET_BITS, bucket);
+
+        byte_tx += 32 + 32;
+        if merkle_bucket_digest(&members1) == merkle_bucket_digest(&members2) {
+            continue;
+        }
+
+        if members1.len() <= MERKLE_LEAF_MAX && members2.len() <= MERKLE_LEAF_MAX {
+            for h in &members1 {
+                byte_tx += h.len();
+                if !map2.contains(*h) {
+                    map2.insert((*h).clone());
+                }
+            }
+            for h in &members2 {
+                byte_tx += h.len();
+                if !map1.contains(*h) {
+                    map1.insert((*h).clone());
+                }
+            }
+        } else {
+            let sub1: Vec<Hash> = members1.into_iter().cloned().collect();
+            let sub2: Vec<Hash> = members2.into_iter().cloned().collect();
+            byte_tx += merkle_reconcile(&sub1, &sub2, bit_offset + MERKLE_BUCKET_BITS, map1, map2);
+        }
+    }
+
+    byte_tx
+}
+
+fn merkle_rehash_filter_sync_two_maps(map1: &mut Map, map2: &mut Map) -> BytesTransferred {
+    let mut hashes1: Vec<Hash> = map1.iter().cloned().collect();
+    let mut hashes2: Vec<Hash> = map2.iter().cloned().collect();
+    hashes1.sort();
+    hashes2.sort();
+
+    let top1 = hash_of_hashes(hashes1.iter());
+    let top2 = hash_of_hashes(hashes2.iter());
+
+    let mut byte_tx = 32 + 32;
+
+    if top1 == top2 {
+        return byte_tx;
+    }
+
+    byte_tx += merkle_reconcile(&hashes1, &hashes2, 0, map1, map2);
+
+    byte_tx
+}
+
+fn merkle_rehash_filter_sync_first_map_to_others(network: &mut Network) -> BytesTransferred {
+    let mut byte_tx = 0;
+    let mut first_node = network.remove(0);
+    {
+        let first_map = first_node.get_mut(0).unwrap();
+
+        for node in network.iter_mut() {
+            byte_tx += merkle_rehash_filter_sync_two_maps(node.get_mut(0).unwrap(), first_map);
+        }
+    }
+
+    network.push(first_node);
+
+    byte_tx
+}
+
+pub fn rehash_filter_sync_first_map_to_others(network: &mut Network) -> BytesTransferred {
+    let mut byte_tx = 0;
+    let mut first_node = network.remove(0);
+    {
+        let first_map = first_node.get_mut(0).unwrap();
+
+        for node in network.iter_mut() {
+            byte_tx += rehash_filter_sync_two_maps(node.get_mut(0).unwrap(), first_map);
+        }
+    }
+
+    network.push(first_node);
+
+    byte_tx
+}
+
+// Push-based gossip, based on Solana's CRDS push path. Each node keeps a
+// small random "active set" of peer indices it pushes newly-learned
+// hashes to. A recipient that doesn't already have a pushed hash accepts
+// it and re-pushes it onward to its own active set, bounded by a hop/TTL
+// limit; a recipient that keeps hearing hashes it already has from a
+// given peer sends that peer a prune, removing the edge from the
+// sender's active set. Unlike the pull-style `*_sync_first_map_to_others`
+// functions this needs state (active sets, dedupe counters) that persists
+// across rounds, so it owns its own run loop rather than plugging into
+// `net_sync_fn`/`test_run`.
+const PUSH_GOSSIP_FANOUT: usize = 6;
+const PUSH_GOSSIP_TTL: u8 = 6;
+const PUSH_GOSSIP_PRUNE_THRESHOLD: usize = 3;
+
+fn push_gossip_active_sets(rng: &mut impl Rng, net_fact: usize) -> Vec<Vec<usize>> {
+    (0..net_fact)
+        .map(|i| {
+            let mut peers: Vec<usize> = (0..net_fact).filter(|&j| j != i).collect();
+            peers.shuffle(rng);
+            peers.truncate(PUSH_GOSSIP_FANOUT.min(peers.len()));
+            peers
+        })
+        .collect()
+}
+
+fn push_gossip_round(
+    network: &mut Network,
+    already_pushed: &mut [Map],
+    active_sets: &mut [Vec<usize>],
+    dup_counts: &mut [std::collections::HashMap<usize, usize>],
+) -> BytesTransferred {
+    let net_fact = network.len();
+    let mut byte_tx = 0;
+
+    let mut queue: std::collections::VecDeque<(usize, usize, Hash, u8)> =
+        std::collections::VecDeque::new();
+
+    for i in 0..net_fact {
+        let new_hashes: Vec<Hash> = network[i]
+            .get(0)
+            .unwrap()
+            .iter()
+            .filter(|h| !already_pushed[i].contains(*h))
+            .cloned()
+            .collect();
+        for h in &new_hashes {
+            already_pushed[i].insert(h.clone());
+        }
+        for &peer in active_sets[i].iter() {
+            for h in &new_hashes {
+                queue.push_back((i, peer, h.clone(), PUSH_GOSSIP_TTL));
+            }
+        }
+    }
+
+    let mut prunes: Vec<(usize, usize)> = Vec::new();
+
+    while let Some((from, to, hash, ttl)) = queue.pop_front() {
+        byte_tx += hash.len();
+
+        if network[to].get(0).unwrap().contains(&hash) {
+            let dup = dup_counts[to].entry(from).or_insert(0);
+            *dup += 1;
+            if *dup >= PUSH_GOSSIP_PRUNE_THRESHOLD {
+                prunes.push((to, from));
+            }
+            continue;
+        }
+
+        dup_counts[to].insert(from, 0);
+        network[to].get_mut(0).unwrap().insert(hash.clone());
+        already_pushed[to].insert(hash.clone());
+
+        if ttl > 0 {
+            for &peer in active_sets[to].iter() {
+                if peer != from {
+                    queue.push_back((to, peer, hash.clone(), ttl - 1));
+                }
+            }
+        }
+    }
+
+    for (pruner, pruned) in prunes {
+        byte_tx += std::mem::size_of::<usize>(); // prune message carries a peer index
+        active_sets[pruned].retain(|&p| p != pruner);
+    }
+
+    byte_tx
+}
+
+fn push_gossip_run(
+    rng: &mut impl Rng,
+    data_count: usize,
+    net_fact: usize,
+) -> Option<(IterationCount, BytesTransferred, SyncTime)> {
+    let mut network = gen_network(rng, data_count, net_fact);
+    assert!(!is_network_consistent(&network));
+    for node in network.iter_mut() {
+        assert!(!is_node_consistent(node));
+        sync_node(node);
+        assert!(is_node_consistent(node));
+    }
+    assert!(!is_network_consistent(&network));
+
+    let mut active_sets = push_gossip_active_sets(rng, net_fact);
+    let mut already_pushed: Vec<Map> = vec![HashSet::new(); net_fact];
+    let mut dup_counts: Vec<std::collections::HashMap<usize, usize>> =
+        vec![std::collections::HashMap::new(); net_fact];
+
+    let start = std::time::Instant::now();
+    let mut byte_tx = 0;
+    let mut count = 0;
+    loop {
+        count += 1;
+
+        byte_tx += push_gossip_round(
+            &mut network,
+            &mut already_pushed,
+            &mut active_sets,
+            &mut dup_counts,
+        );
+
+        sync_network(&mut network);
+
+        if is_network_consistent(&network) {
+            break;
+        }
+
+        // active-set pruning only ever removes edges, so a burst of
+        // duplicate pushes during the initial flood can partition the
+        // push graph before every node has every hash; bound the run
+        // rather than looping forever on a partitioned graph
+        if count >= MAX_ITERATIONS {
+            return None;
+        }
+    }
+
+    Some((count, byte_tx, start.elapsed()))
+}
+
+pub fn push_gossip_test_suite(data_count: usize, net_fact: usize) {
+    println!(
+        "running with {} ops / {}x{} nodes",
+        data_count, net_fact, net_fact
+    );
+    use std::io::Write;
+    let mut stdout = std::io::stdout();
+    let mut rng = StdRng::from_entropy();
+
+    write!(stdout, "push_gossip warmup ").unwrap();
+    stdout.flush().unwrap();
+    for _ in 1..=3 {
+        write!(stdout, ".").unwrap();
+        stdout.flush().unwrap();
+        push_gossip_run(&mut rng, data_count, net_fact);
+    }
+
+    let mut it_count = Vec::new();
+    let mut byte_tx = Vec::new();
+    let mut sync_time = Vec::new();
+    let mut non_convergent = 0;
+
+    write!(stdout, "push_gossip test ").unwrap();
+    stdout.flush().unwrap();
+    for _ in 1..=20 {
+        write!(stdout, ".").unwrap();
+        stdout.flush().unwrap();
+        match push_gossip_run(&mut rng, data_count, net_fact) {
+            Some((it, bt, tt)) => {
+                it_count.push(it);
+                byte_tx.push(bt as f64 / 1024.0 / 1024.0);
+                sync_time.push(tt.as_secs_f64());
+            }
+            None => {
+                println!("push_gossip non-convergence after {} iterations", MAX_ITERATIONS);
+                non_convergent += 1;
+            }
+        }
+    }
+    println!("done.");
+
+    use stats::*;
+    if it_count.is_empty() {
+        println!("push_gossip: all runs non-convergent");
+        return;
+    }
+
+    println!(
+        "push_gossip iterations: {:.01}±{:.04}, MiB tranferred: {:.04}±{:.04} in {:.04}±{:.04} s, {} non-convergent runs",
+        mean(it_count.iter().cloned()),
+        stddev(it_count.iter().cloned()),
+        mean(byte_tx.iter().cloned()),
+        stddev(byte_tx.iter().cloned()),
+        mean(sync_time.iter().cloned()),
+        stddev(sync_time.iter().cloned()),
+        non_convergent,
+    );
+}
+
+// Network topologies, mirroring Solana's star_network_create /
+// rstar_network_create. `gen_network` still produces a fully random mesh
+// of data, but which node pairs are *allowed* to sync is now controlled
+// separately by a `Topology`, and each node carries a `stake` that biases
+// partner selection among its allowed edges (stake-weighted gossip).
+#[derive(Debug, Clone, Copy)]
+pub enum Topology {
+    Mesh,
+    Star,
+    RingStar,
+    RandomRegular(usize),
+}
+
+fn topology_edges(topology: Topology, net_fact: usize, rng: &mut impl Rng) -> Vec<Vec<usize>> {
+    match topology {
+        Topology::Mesh => (0..net_fact)
+            .map(|i| (0..net_fact).filter(|&j| j != i).collect())
+            .collect(),
+
+        Topology::Star => (0..net_fact)
+            .map(|i| {
+                if i == 0 {
+                    (1..net_fact).collect()
+                } else {
+                    vec![0]
+                }
+            })
+            .collect(),
+
+        Topology::RingStar => {
+            let mut edges: Vec<HashSet<usize>> = vec![HashSet::new(); net_fact];
+            for i in 0..net_fact {
+                let next = (i + 1) % net_fact;
+                edges[i].insert(next);
+                edges[next].insert(i);
+            }
+            for i in 1..net_fact {
+                edges[0].insert(i);
+                edges[i].insert(0);
+            }
+            edges.into_iter().map(|e| e.into_iter().collect()).collect()
+        }
+
+        Topology::RandomRegular(degree) => {
+            let mut edges: Vec<HashSet<usize>> = vec![HashSet::new(); net_fact];
+            let max_attempts = net_fact * degree.max(1) * 10;
+            for _ in 0..max_attempts {
+                if edges.iter().all(|e| e.len() >= degree) {
+                    break;
+                }
+                let mut candidates: Vec<usize> =
+                    (0..net_fact).filter(|&i| edges[i].len() < degree).collect();
+                if candidates.len() < 2 {
+                    break;
+                }
+                candidates.shuffle(rng);
+                let (a, b) = (candidates[0], candidates[1]);
+                if a != b && !edges[a].contains(&b) {
+                    edges[a].insert(b);
+                    edges[b].insert(a);
+                }
+            }
+            edges.into_iter().map(|e| e.into_iter().collect()).collect()
+        }
+    }
+}
+
+fn gen_stakes(rng: &mut impl Rng, net_fact: usize) -> Vec<u64> {
+    (0..net_fact)
+        .map(|_| 1 + (rng.gen::<f64>().powi(3) * 1000.0) as u64)
+        .collect()
+}
+
+fn pick_stake_weighted_peer(rng: &mut impl Rng, peers: &[usize], stakes: &[u64]) -> Option<usize> {
+    if peers.is_empty() {
+        return None;
+    }
+    let total: u64 = peers.iter().map(|&p| stakes[p]).sum();
+    let mut choice = rng.gen_range(0..total);
+    for &p in peers {
+        if choice < stakes[p] {
+            return Some(p);
+        }
+        choice -= stakes[p];
+    }
+    peers.last().copied()
+}
+
+fn topology_sync_round(
+    rng: &mut impl Rng,
+    network: &mut Network,
+    edges: &[Vec<usize>],
+    stakes: &[u64],
+    two_map_sync_fn: fn(&mut Map, &mut Map) -> BytesTransferred,
+) -> BytesTransferred {
+    let net_fact = network.len();
+    let mut byte_tx = 0;
+    let mut paired = vec![false; net_fact];
+
+    let mut order: Vec<usize> = (0..net_fact).collect();
+    order.shuffle(rng);
+
+    for i in order {
+        if paired[i] {
+            continue;
+        }
+        let candidates: Vec<usize> = edges[i].iter().cloned().filter(|&p| !paired[p]).collect();
+        if let Some(peer) = pick_stake_weighted_peer(rng, &candidates, stakes) {
+            paired[i] = true;
+            paired[peer] = true;
+
+            let (lo, hi) = if i < peer { (i, peer) } else { (peer, i) };
+            let (left, right) = network.split_at_mut(hi);
+            byte_tx += two_map_sync_fn(
+                left[lo].get_mut(0).unwrap(),
+                right[0].get_mut(0).unwrap(),
+            );
+        }
+    }
+
+    byte_tx
+}
+
+fn topology_run(
+    rng: &mut impl Rng,
+    data_count: usize,
+    net_fact: usize,
+    topology: Topology,
+    two_map_sync_fn: fn(&mut Map, &mut Map) -> BytesTransferred,
+) -> Option<(IterationCount, BytesTransferred, SyncTime)> {
+    let mut network = gen_network(rng, data_count, net_fact);
+    assert!(!is_network_consistent(&network));
+    for node in network.iter_mut() {
+        assert!(!is_node_consistent(node));
+        sync_node(node);
+        assert!(is_node_consistent(node));
+    }
+    assert!(!is_network_consistent(&network));
+
+    let edges = topology_edges(topology, net_fact, rng);
+    let stakes = gen_stakes(rng, net_fact);
+
+    let start = std::time::Instant::now();
+    let mut byte_tx = 0;
+    let mut count = 0;
+    loop {
+        count += 1;
+
+        byte_tx += topology_sync_round(rng, &mut network, &edges, &stakes, two_map_sync_fn);
+        sync_network(&mut network);
+
+        if is_network_consistent(&network) {
+            break;
+        }
+
+        // sparse topologies (Star leaves, low-degree RandomRegular) can take
+        // far longer to converge than a dense mesh, and a RandomRegular(d)
+        // graph that couldn't satisfy degree d for every node may never
+        // converge at all; bound the run and report non-convergence rather
+        // than folding a bogus capped iteration count into the stats
+        if count >= MAX_ITERATIONS {
+            return None;
+        }
+    }
+
+    Some((count, byte_tx, start.elapsed()))
+}
+
+pub fn topology_test_suite(
+    name: &str,
+    data_count: usize,
+    net_fact: usize,
+    topology: Topology,
+    two_map_sync_fn: fn(&mut Map, &mut Map) -> BytesTransferred,
+) {
+    println!(
+        "running {} with {} ops / {}x{} nodes",
+        name, data_count, net_fact, net_fact
+    );
+    use std::io::Write;
+    let mut stdout = std::io::stdout();
+    let mut rng = StdRng::from_entropy();
+
+    write!(stdout, "{} warmup ", name).unwrap();
+    stdout.flush().unwrap();
+    for _ in 1..=3 {
+        write!(stdout, ".").unwrap();
+        stdout.flush().unwrap();
+        topology_run(&mut rng, data_count, net_fact, topology, two_map_sync_fn);
+    }
+
+    let mut it_count = Vec::new();
+    let mut byte_tx = Vec::new();
+    let mut sync_time = Vec::new();
+    let mut non_convergent = 0;
+
+    write!(stdout, "{} test ", name).unwrap();
+    stdout.flush().unwrap();
+    for _ in 1..=20 {
+        write!(stdout, ".").unwrap();
+        stdout.flush().unwrap();
+        match topology_run(&mut rng, data_count, net_fact, topology, two_map_sync_fn) {
+            Some((it, bt, tt)) => {
+                it_count.push(it);
+                byte_tx.push(bt as f64 / 1024.0 / 1024.0);
+                sync_time.push(tt.as_secs_f64());
+            }
+            None => {
+                println!("{} non-convergence after {} iterations", name, MAX_ITERATIONS);
+                non_convergent += 1;
+            }
+        }
+    }
+    println!("done.");
+
+    use stats::*;
+    if it_count.is_empty() {
+        println!("{}: all runs non-convergent", name);
+        return;
+    }
+
+    println!(
+        "{} iterations: {:.01}±{:.04}, MiB tranferred: {:.04}±{:.04} in {:.04}±{:.04} s, {} non-convergent runs",
+        name,
+        mean(it_count.iter().cloned()),
+        stddev(it_count.iter().cloned()),
+        mean(byte_tx.iter().cloned()),
+        stddev(byte_tx.iter().cloned()),
+        mean(sync_time.iter().cloned()),
+        stddev(sync_time.iter().cloned()),
+        non_convergent,
+    );
 }
 
-fn rehash_filter_sync_two_maps(map1: &mut Map, map2: &mut Map) -> BytesTransferred {
-    let mut byte_tx = 0;
-    let hash1 = hash_of_hashes(map1.iter());
-    let hash2 = hash_of_hashes(map2.iter());
-    byte_tx += 32 + 32;
+type TwoMapSyncFn = fn(&mut Map, &mut Map) -> BytesTransferred;
 
-    if hash1 != hash2 {
-        // node 1 sends all hashes
-        byte_tx += map1.len() * 32;
+pub fn topology_comparison_test_suite(data_count: usize, net_fact: usize) {
+    let topologies: [(&'static str, Topology); 4] = [
+        ("mesh", Topology::Mesh),
+        ("star", Topology::Star),
+        ("ring_star", Topology::RingStar),
+        ("random_regular_4", Topology::RandomRegular(4)),
+    ];
+    let sync_fns: [(&'static str, TwoMapSyncFn); 2] = [
+        ("bloom", bloom_filter_sync_two_maps),
+        ("rehash", rehash_filter_sync_two_maps),
+    ];
 
-        // node 2 requests ops it doesn't have from node 1
-        for h in map1.iter() {
-            if !map2.contains(h) {
-                byte_tx += h.len();
-                map2.insert(h.clone());
-            }
+    for (topo_name, topology) in topologies {
+        for (sync_name, sync_fn) in sync_fns {
+            let name = format!("{}_{}", topo_name, sync_name);
+            topology_test_suite(&name, data_count, net_fact, topology, sync_fn);
         }
+    }
+}
 
-        // node 2 forwards ops it has that node 1 doesn't
-        for h in map2.iter() {
-            if !map1.contains(h) {
-                byte_tx += h.len();
-                map1.insert(h.clone());
+// Value timestamps with timeout-based expiry, matching Solana's
+// CRDS_GOSSIP_PULL_CRDS_TIMEOUT_MS semantics: a parallel map of wallclock
+// timestamps tracks when each hash was last (re)inserted, and entries
+// older than `timeout` ticks are pruned from a node during churn. Unlike
+// `test_run`, which measures one-shot convergence from a fixed starting
+// set, churn runs for a fixed number of ticks while continuously
+// inserting fresh hashes and expiring stale ones, to measure steady-state
+// reconciliation bandwidth instead.
+type WallClock = u64;
+type Timestamps = std::collections::HashMap<Hash, WallClock>;
+
+const CHURN_DEFAULT_TIMEOUT: WallClock = 50;
+const CHURN_ITERATIONS: usize = 200;
+const CHURN_INSERTS_PER_TICK: usize = 2;
+
+fn expire_stale(map: &mut Map, timestamps: &mut Timestamps, now: WallClock, timeout: WallClock) {
+    let stale: Vec<Hash> = timestamps
+        .iter()
+        .filter(|(_, &ts)| now.saturating_sub(ts) > timeout)
+        .map(|(h, _)| h.clone())
+        .collect();
+    for h in stale {
+        map.remove(&h);
+        timestamps.remove(&h);
+    }
+}
+
+fn shuffle_network_with_timestamps(
+    rng: &mut impl Rng,
+    network: &mut Network,
+    timestamps: &mut Vec<Timestamps>,
+) {
+    // unlike shuffle_network, this does NOT reshuffle each node's inner
+    // Vec<Map>: index 0 is the only slot churn_run ever mutates/prunes/
+    // timestamps, so reshuffling it would swap in a frozen post-setup
+    // replica and silently resurrect whatever expire_stale just removed
+    let net_fact = network.len();
+    let mut order: Vec<usize> = (0..net_fact).collect();
+    order.shuffle(rng);
+
+    let mut new_network = Vec::with_capacity(net_fact);
+    let mut new_timestamps = Vec::with_capacity(net_fact);
+    for i in order {
+        new_network.push(std::mem::take(&mut network[i]));
+        new_timestamps.push(std::mem::take(&mut timestamps[i]));
+    }
+    *network = new_network;
+    *timestamps = new_timestamps;
+}
+
+fn churn_run(
+    rng: &mut impl Rng,
+    data_count: usize,
+    net_fact: usize,
+    timeout: WallClock,
+    net_sync_fn: fn(&mut Network) -> BytesTransferred,
+) -> BytesTransferred {
+    let mut network = gen_network(rng, data_count, net_fact);
+    for node in network.iter_mut() {
+        sync_node(node);
+    }
+
+    let mut timestamps: Vec<Timestamps> = network
+        .iter()
+        .map(|node| node.get(0).unwrap().iter().map(|h| (h.clone(), 0)).collect())
+        .collect();
+
+    let mut now: WallClock = 0;
+    let mut byte_tx: BytesTransferred = 0;
+
+    for _ in 0..CHURN_ITERATIONS {
+        now += 1;
+
+        for _ in 0..CHURN_INSERTS_PER_TICK {
+            let idx = rng.gen_range(0..net_fact);
+            let h = rand_hash(rng);
+            network[idx].get_mut(0).unwrap().insert(h.clone());
+            timestamps[idx].insert(h, now);
+        }
+
+        for (node, ts) in network.iter_mut().zip(timestamps.iter_mut()) {
+            expire_stale(node.get_mut(0).unwrap(), ts, now, timeout);
+        }
+
+        shuffle_network_with_timestamps(rng, &mut network, &mut timestamps);
+        byte_tx += net_sync_fn(&mut network);
+        // the *_first_map_to_others syncs move the source node from the
+        // front of the network to the back; keep timestamps[i] paired
+        // with network[i]
+        timestamps.rotate_left(1);
+
+        // stamp hashes a node just learned about from a peer so their
+        // expiry clock starts now, not at the (missing) insert time;
+        // note we deliberately skip sync_network() here, since the
+        // untouched replica maps it would union back in (node[1..]) were
+        // never pruned and would resurrect anything expire_stale just
+        // removed from node[0]
+        for (node, ts) in network.iter_mut().zip(timestamps.iter_mut()) {
+            for h in node.get(0).unwrap().iter() {
+                ts.entry(h.clone()).or_insert(now);
             }
         }
     }
@@ -205,32 +1020,215 @@ fn rehash_filter_sync_two_maps(map1: &mut Map, map2: &mut Map) -> BytesTransferr
     byte_tx
 }
 
-fn rehash_filter_sync_first_map_to_others(network: &mut Network) -> BytesTransferred {
-    let mut byte_tx = 0;
-    let mut first_node = network.remove(0);
-    {
-        let first_map = first_node.get_mut(0).unwrap();
+pub fn churn_test_suite(
+    name: &str,
+    data_count: usize,
+    net_fact: usize,
+    net_sync_fn: fn(&mut Network) -> BytesTransferred,
+) {
+    println!(
+        "running churn {} with {} ops / {}x{} nodes, timeout={} ticks",
+        name, data_count, net_fact, net_fact, CHURN_DEFAULT_TIMEOUT
+    );
+    use std::io::Write;
+    let mut stdout = std::io::stdout();
+    let mut rng = StdRng::from_entropy();
 
-        for node in network.iter_mut() {
-            byte_tx += rehash_filter_sync_two_maps(node.get_mut(0).unwrap(), first_map);
-        }
+    write!(stdout, "{} churn warmup ", name).unwrap();
+    stdout.flush().unwrap();
+    for _ in 1..=3 {
+        write!(stdout, ".").unwrap();
+        stdout.flush().unwrap();
+        churn_run(&mut rng, data_count, net_fact, CHURN_DEFAULT_TIMEOUT, net_sync_fn);
     }
 
-    network.push(first_node);
+    let mut byte_tx_per_tick = Vec::new();
 
-    byte_tx
+    write!(stdout, "{} churn test ", name).unwrap();
+    stdout.flush().unwrap();
+    for _ in 1..=20 {
+        write!(stdout, ".").unwrap();
+        stdout.flush().unwrap();
+        let bt = churn_run(&mut rng, data_count, net_fact, CHURN_DEFAULT_TIMEOUT, net_sync_fn);
+        byte_tx_per_tick.push(bt as f64 / 1024.0 / 1024.0 / CHURN_ITERATIONS as f64);
+    }
+    println!("done.");
+
+    use stats::*;
+    println!(
+        "{} churn MiB transferred per tick: {:.06}±{:.06}",
+        name,
+        mean(byte_tx_per_tick.iter().cloned()),
+        stddev(byte_tx_per_tick.iter().cloned()),
+    );
+}
+
+pub fn bloom_churn_test_suite(data_count: usize, net_fact: usize) {
+    churn_test_suite(
+        "bloom",
+        data_count,
+        net_fact,
+        bloom_filter_sync_first_map_to_others,
+    );
+}
+
+pub fn rehash_churn_test_suite(data_count: usize, net_fact: usize) {
+    churn_test_suite(
+        "rehash",
+        data_count,
+        net_fact,
+        rehash_filter_sync_first_map_to_others,
+    );
 }
 
 type IterationCount = usize;
 type SyncTime = std::time::Duration;
 
-fn test_run(
+// Adversary hook, echoing hbbft's adversary model: called once per round,
+// before and after the honest net_sync_fn exchange, so a test can inject
+// spurious data, drop a node's exchange for the round, or bias who pairs
+// up with whom. Default methods are no-ops so an adversary only has to
+// override the hook(s) it cares about.
+pub trait Adversary {
+    fn before_round(&mut self, _rng: &mut StdRng, _network: &mut Network) {}
+    fn after_round(&mut self, _rng: &mut StdRng, _network: &mut Network) {}
+}
+
+pub struct NullAdversary;
+
+impl Adversary for NullAdversary {}
+
+/// Inserts a spurious random hash into a fraction of nodes each round.
+pub struct SpuriousHashAdversary {
+    pub fraction: f64,
+}
+
+impl Adversary for SpuriousHashAdversary {
+    fn before_round(&mut self, rng: &mut StdRng, network: &mut Network) {
+        for node in network.iter_mut() {
+            if rng.gen::<f64>() < self.fraction {
+                let h = rand_hash(rng);
+                node.get_mut(0).unwrap().insert(h);
+            }
+        }
+    }
+}
+
+/// Drops a fraction of nodes from this round's sync exchange entirely, by
+/// taking them out of the network before the round and putting them back
+/// after. At least one node always stays in the round.
+pub struct DropNodeAdversary {
+    pub fraction: f64,
+    stash: Vec<Node>,
+}
+
+impl DropNodeAdversary {
+    pub fn new(fraction: f64) -> Self {
+        Self {
+            fraction,
+            stash: Vec::new(),
+        }
+    }
+}
+
+impl Adversary for DropNodeAdversary {
+    fn before_round(&mut self, rng: &mut StdRng, network: &mut Network) {
+        self.stash.clear();
+        // back to front, so a removal doesn't shift indices still to visit
+        for idx in (0..network.len()).rev() {
+            if rng.gen::<f64>() < self.fraction && network.len() > 1 {
+                self.stash.push(network.remove(idx));
+            }
+        }
+    }
+
+    fn after_round(&mut self, _rng: &mut StdRng, network: &mut Network) {
+        network.extend(self.stash.drain(..));
+    }
+}
+
+/// Always puts the node with the most data at index 0 (the source node in
+/// `*_first_map_to_others` syncs), instead of leaving who syncs first to
+/// the network-wide shuffle. Note this has to be a data-dependent
+/// reordering, not just another permutation: `shuffle_network` already
+/// randomizes node order every round, and composing a fixed permutation
+/// with an already-uniform-random one is still uniform random, so it
+/// wouldn't actually bias anything.
+pub struct ReorderAdversary;
+
+impl Adversary for ReorderAdversary {
+    fn before_round(&mut self, _rng: &mut StdRng, network: &mut Network) {
+        network.sort_by_key(|node| std::cmp::Reverse(node.first().map_or(0, |m| m.len())));
+    }
+}
+
+/// A maximum iteration count past which a run is declared non-convergent
+/// rather than looping forever against a misbehaving adversary.
+const MAX_ITERATIONS: usize = 10_000;
+
+#[derive(Debug)]
+pub struct NonConvergence {
+    pub seed: [u8; 32],
+    pub iterations: usize,
+}
+
+impl std::fmt::Display for NonConvergence {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "did not converge after {} iterations (seed {})",
+            self.iterations,
+            format_seed(&self.seed),
+        )
+    }
+}
+
+impl std::error::Error for NonConvergence {}
+
+fn format_seed(seed: &[u8; 32]) -> String {
+    seed.iter().map(|b| format!("{:02x}", b)).collect()
+}
+
+/// Parses a seed printed by `format_seed` (e.g. from a `NonConvergence` or
+/// a `test_run seed:` line) back into the byte form `test_run_seeded`
+/// expects, so a non-converging or slow run can actually be replayed.
+pub fn parse_seed(s: &str) -> Option<[u8; 32]> {
+    if s.len() != 64 {
+        return None;
+    }
+    let mut seed = [0u8; 32];
+    for (i, b) in seed.iter_mut().enumerate() {
+        *b = u8::from_str_radix(&s[i * 2..i * 2 + 2], 16).ok()?;
+    }
+    Some(seed)
+}
+
+/// Runs a single honest-or-adversarial trial against a caller-supplied
+/// seed, so a seed printed by a prior run (honest or adversarial) can be
+/// replayed to reproduce a slow or non-converging run. Bloom filter sip
+/// keys are not drawn from this seed (see below).
+pub fn test_run_seeded(
     data_count: usize,
     net_fact: usize,
     net_sync_fn: fn(&mut Network) -> BytesTransferred,
-) -> (IterationCount, BytesTransferred, SyncTime) {
+    adversary: &mut dyn Adversary,
+    seed: Option<[u8; 32]>,
+) -> Result<(IterationCount, BytesTransferred, SyncTime), NonConvergence> {
+    // network generation, shuffling and adversary choices are driven off
+    // this seed, which is captured and printed up front so a non-converging
+    // or slow run can be replayed; bloomfilter::Bloom draws its own sip
+    // keys, not from this seed, so the false positives of a bloom-based
+    // net_sync_fn can differ between replays of the same seed
+    let seed = seed.unwrap_or_else(|| {
+        let mut s = [0; 32];
+        rand::thread_rng().fill(&mut s[..]);
+        s
+    });
+    println!("test_run seed: {}", format_seed(&seed));
+    let mut rng = StdRng::from_seed(seed);
+
     // generate a random network
-    let mut network = gen_network(data_count, net_fact);
+    let mut network = gen_network(&mut rng, data_count, net_fact);
 
     // make sure the network is not consistent
     assert!(!is_network_consistent(&network));
@@ -255,9 +1253,17 @@ fn test_run(
     let mut count = 0;
     loop {
         count += 1;
+        if count > MAX_ITERATIONS {
+            return Err(NonConvergence {
+                seed,
+                iterations: count - 1,
+            });
+        }
 
         // randomize which nodes speak to which nodes
-        shuffle_network(&mut network);
+        shuffle_network(&mut rng, &mut network);
+
+        adversary.before_round(&mut rng, &mut network);
 
         // run our inter-node syncro code
         byte_tx += net_sync_fn(&mut network);
@@ -265,13 +1271,75 @@ fn test_run(
         // sync the maps in individual nodes
         sync_network(&mut network);
 
+        adversary.after_round(&mut rng, &mut network);
+
         // check for consistency
         if is_network_consistent(&network) {
             break;
         }
     }
 
-    (count, byte_tx, start.elapsed())
+    Ok((count, byte_tx, start.elapsed()))
+}
+
+fn test_run(
+    data_count: usize,
+    net_fact: usize,
+    net_sync_fn: fn(&mut Network) -> BytesTransferred,
+) -> (IterationCount, BytesTransferred, SyncTime) {
+    test_run_seeded(data_count, net_fact, net_sync_fn, &mut NullAdversary, None)
+        .expect("an honest network should always converge")
+}
+
+pub fn adversarial_test_suite(
+    name: &'static str,
+    data_count: usize,
+    net_fact: usize,
+    net_sync_fn: fn(&mut Network) -> BytesTransferred,
+    mut make_adversary: impl FnMut() -> Box<dyn Adversary>,
+) {
+    println!(
+        "running adversarial {} with {} ops / {}x{} nodes",
+        name, data_count, net_fact, net_fact
+    );
+
+    let mut it_count = Vec::new();
+    let mut byte_tx = Vec::new();
+    let mut sync_time = Vec::new();
+    let mut non_convergent = 0;
+
+    for _ in 1..=20 {
+        let mut adversary = make_adversary();
+        match test_run_seeded(data_count, net_fact, net_sync_fn, adversary.as_mut(), None) {
+            Ok((it, bt, tt)) => {
+                it_count.push(it);
+                byte_tx.push(bt as f64 / 1024.0 / 1024.0);
+                sync_time.push(tt.as_secs_f64());
+            }
+            Err(e) => {
+                println!("{} non-convergence: {}", name, e);
+                non_convergent += 1;
+            }
+        }
+    }
+
+    use stats::*;
+    if it_count.is_empty() {
+        println!("{}: all runs non-convergent", name);
+        return;
+    }
+
+    println!(
+        "{} iterations: {:.01}±{:.04}, MiB tranferred: {:.04}±{:.04} in {:.04}±{:.04} s, {} non-convergent runs",
+        name,
+        mean(it_count.iter().cloned()),
+        stddev(it_count.iter().cloned()),
+        mean(byte_tx.iter().cloned()),
+        stddev(byte_tx.iter().cloned()),
+        mean(sync_time.iter().cloned()),
+        stddev(sync_time.iter().cloned()),
+        non_convergent,
+    );
 }
 
 fn test_suite(
@@ -342,6 +1410,123 @@ pub fn rehash_test_suite(data_count: usize, net_fact: usize) {
     );
 }
 
+// bloom_partitioned_filter_sync_two_maps needs a source of randomness for
+// its partition prefix, which the shared `net_sync_fn: fn(&mut Network) ->
+// BytesTransferred` signature used by `test_suite` has no room for; like
+// `push_gossip_run`, it owns its own run loop instead of plugging into
+// `test_run`/`test_suite` so that randomness can be threaded from the
+// run's own seeded rng rather than an independent `thread_rng()`.
+fn bloom_partitioned_run(
+    rng: &mut impl Rng,
+    data_count: usize,
+    net_fact: usize,
+) -> Option<(IterationCount, BytesTransferred, SyncTime)> {
+    let mut network = gen_network(rng, data_count, net_fact);
+    assert!(!is_network_consistent(&network));
+    for node in network.iter_mut() {
+        assert!(!is_node_consistent(node));
+        sync_node(node);
+        assert!(is_node_consistent(node));
+    }
+    assert!(!is_network_consistent(&network));
+
+    let start = std::time::Instant::now();
+    let mut byte_tx = 0;
+    let mut count = 0;
+    loop {
+        count += 1;
+
+        shuffle_network(rng, &mut network);
+        byte_tx += bloom_partitioned_filter_sync_first_map_to_others(rng, &mut network);
+        sync_network(&mut network);
+
+        if is_network_consistent(&network) {
+            break;
+        }
+
+        // each call only exchanges one randomly-chosen partition per node
+        // pair, so convergence is a coupon-collector process with no
+        // upper bound on its own; bound the run rather than looping
+        // forever on an unlucky seed
+        if count >= MAX_ITERATIONS {
+            return None;
+        }
+    }
+
+    Some((count, byte_tx, start.elapsed()))
+}
+
+pub fn bloom_partitioned_test_suite(data_count: usize, net_fact: usize) {
+    println!(
+        "running with {} ops / {}x{} nodes",
+        data_count, net_fact, net_fact
+    );
+    use std::io::Write;
+    let mut stdout = std::io::stdout();
+    let mut rng = StdRng::from_entropy();
+
+    write!(stdout, "bloom_partitioned warmup ").unwrap();
+    stdout.flush().unwrap();
+    for _ in 1..=3 {
+        write!(stdout, ".").unwrap();
+        stdout.flush().unwrap();
+        bloom_partitioned_run(&mut rng, data_count, net_fact);
+    }
+
+    let mut it_count = Vec::new();
+    let mut byte_tx = Vec::new();
+    let mut sync_time = Vec::new();
+    let mut non_convergent = 0;
+
+    write!(stdout, "bloom_partitioned test ").unwrap();
+    stdout.flush().unwrap();
+    for _ in 1..=20 {
+        write!(stdout, ".").unwrap();
+        stdout.flush().unwrap();
+        match bloom_partitioned_run(&mut rng, data_count, net_fact) {
+            Some((it, bt, tt)) => {
+                it_count.push(it);
+                byte_tx.push(bt as f64 / 1024.0 / 1024.0);
+                sync_time.push(tt.as_secs_f64());
+            }
+            None => {
+                println!(
+                    "bloom_partitioned non-convergence after {} iterations",
+                    MAX_ITERATIONS
+                );
+                non_convergent += 1;
+            }
+        }
+    }
+    println!("done.");
+
+    use stats::*;
+    if it_count.is_empty() {
+        println!("bloom_partitioned: all runs non-convergent");
+        return;
+    }
+
+    println!(
+        "bloom_partitioned iterations: {:.01}±{:.04}, MiB tranferred: {:.04}±{:.04} in {:.04}±{:.04} s, {} non-convergent runs",
+        mean(it_count.iter().cloned()),
+        stddev(it_count.iter().cloned()),
+        mean(byte_tx.iter().cloned()),
+        stddev(byte_tx.iter().cloned()),
+        mean(sync_time.iter().cloned()),
+        stddev(sync_time.iter().cloned()),
+        non_convergent,
+    );
+}
+
+pub fn merkle_rehash_test_suite(data_count: usize, net_fact: usize) {
+    test_suite(
+        "merkle_rehash",
+        data_count,
+        net_fact,
+        merkle_rehash_filter_sync_first_map_to_others,
+    );
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -350,5 +1535,99 @@ mod tests {
     fn test() {
         bloom_test_suite(20, 10);
         rehash_test_suite(20, 10);
+        bloom_partitioned_test_suite(20, 10);
+        merkle_rehash_test_suite(20, 10);
+        push_gossip_test_suite(20, 10);
+        topology_test_suite(
+            "mesh_bloom",
+            20,
+            10,
+            Topology::Mesh,
+            bloom_filter_sync_two_maps,
+        );
+        topology_test_suite(
+            "star_rehash",
+            20,
+            10,
+            Topology::Star,
+            rehash_filter_sync_two_maps,
+        );
+        topology_test_suite(
+            "ring_star_bloom",
+            20,
+            10,
+            Topology::RingStar,
+            bloom_filter_sync_two_maps,
+        );
+        topology_test_suite(
+            "random_regular_4_rehash",
+            20,
+            10,
+            Topology::RandomRegular(4),
+            rehash_filter_sync_two_maps,
+        );
+        bloom_churn_test_suite(20, 10);
+        rehash_churn_test_suite(20, 10);
+    }
+
+    #[test]
+    fn test_bloom_partitioned_multi_partition() {
+        // partition_mask_bits is computed from map sizes, which start at
+        // data_count * net_fact once each node is synced; that must exceed
+        // PARTITION_TARGET_COUNT so the mask is nonzero from the first round
+        // and the sync actually exercises more than one partition
+        assert!(1200 * 4 > PARTITION_TARGET_COUNT);
+        bloom_partitioned_test_suite(1200, 4);
+    }
+
+    #[test]
+    fn test_seeded_replay() {
+        let seed = [7u8; 32];
+        let run = |s| {
+            test_run_seeded(
+                20,
+                10,
+                bloom_filter_sync_first_map_to_others,
+                &mut NullAdversary,
+                Some(s),
+            )
+            .expect("an honest network should always converge")
+        };
+        // bloomfilter::Bloom randomizes its own sip_keys per construction,
+        // independent of the rng threaded through here, so byte_tx (which
+        // depends on bloom false-positive rate) isn't byte-for-byte
+        // reproducible; the iteration count -- which only depends on node
+        // pairing and insert order, both driven by the seeded rng -- is.
+        let (it1, _bt1, _) = run(seed);
+        let (it2, _bt2, _) = run(seed);
+        assert_eq!(it1, it2);
+
+        let round_tripped = parse_seed(&format_seed(&seed)).unwrap();
+        assert_eq!(round_tripped, seed);
+    }
+
+    #[test]
+    fn test_adversarial() {
+        adversarial_test_suite(
+            "bloom_spurious",
+            20,
+            10,
+            bloom_filter_sync_first_map_to_others,
+            || Box::new(SpuriousHashAdversary { fraction: 0.1 }),
+        );
+        adversarial_test_suite(
+            "rehash_drop_node",
+            20,
+            10,
+            rehash_filter_sync_first_map_to_others,
+            || Box::new(DropNodeAdversary::new(0.1)),
+        );
+        adversarial_test_suite(
+            "bloom_reorder",
+            20,
+            10,
+            bloom_filter_sync_first_map_to_others,
+            || Box::new(ReorderAdversary),
+        );
     }
 }